@@ -160,7 +160,12 @@ impl RolloutRecorder {
         cwd: std::path::PathBuf,
     ) -> std::io::Result<(Self, SavedSession)> {
         info!("Resuming rollout from {path:?}");
-        let text = tokio::fs::read_to_string(path).await?;
+        // Decode lossily so a stray invalid byte (e.g. binary that leaked into
+        // a tool output) only affects the line it appears on rather than
+        // making the whole rollout unreadable.
+        let bytes = tokio::fs::read(path).await?;
+        let text = String::from_utf8_lossy(&bytes);
+        let text = text.strip_prefix('\u{feff}').unwrap_or(&text);
         let mut lines = text.lines();
         let meta_line = lines
             .next()
@@ -360,3 +365,55 @@ impl JsonlWriter {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use pretty_assertions::assert_eq;
+    use tempfile::TempDir;
+
+    #[tokio::test]
+    async fn resume_tolerates_bom_and_invalid_utf8() {
+        let temp_dir = TempDir::new().expect("create temp dir");
+        let path = temp_dir.path().join("rollout.jsonl");
+
+        let mut contents = Vec::new();
+        contents.extend_from_slice("\u{feff}".as_bytes());
+        contents.extend_from_slice(
+            br#"{"id":"5973b6c0-94b8-487b-a530-2aeb6098ae0e","timestamp":"2025-05-07T17:24:21.000Z","instructions":null}"#,
+        );
+        contents.push(b'\n');
+        contents.extend_from_slice(
+            br#"{"type":"message","role":"user","content":[{"type":"input_text","text":"hello"}]}"#,
+        );
+        contents.push(b'\n');
+        contents.extend_from_slice(br#"{"type":"message","role":"assistant","content":[{"type":"output_text","text":"bad "#);
+        contents.extend_from_slice(&[0xff, 0xfe]);
+        contents.extend_from_slice(br#" bytes"}]}"#);
+        contents.push(b'\n');
+        std::fs::write(&path, contents).expect("write rollout");
+
+        let (_recorder, saved) = RolloutRecorder::resume(&path, temp_dir.path().to_path_buf())
+            .await
+            .expect("resume rollout");
+
+        assert_eq!(
+            saved.session_id.to_string(),
+            "5973b6c0-94b8-487b-a530-2aeb6098ae0e"
+        );
+        assert_eq!(saved.items.len(), 2);
+        match &saved.items[1] {
+            ResponseItem::Message { role, content, .. } => {
+                assert_eq!(role, "assistant");
+                assert_eq!(
+                    content,
+                    &vec![crate::models::ContentItem::OutputText {
+                        text: "bad \u{fffd}\u{fffd} bytes".to_string(),
+                    }]
+                );
+            }
+            other => panic!("unexpected item: {other:?}"),
+        }
+    }
+}
@@ -485,6 +485,9 @@ impl App<'_> {
                         self.file_search.on_user_query(query);
                     }
                 }
+                AppEvent::CancelFileSearch => {
+                    self.file_search.cancel();
+                }
                 AppEvent::FileSearchResult { query, matches } => {
                     if let AppState::Chat { widget } = &mut self.app_state {
                         widget.apply_file_search_result(query, matches);
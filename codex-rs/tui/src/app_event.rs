@@ -43,6 +43,10 @@ pub(crate) enum AppEvent {
     /// is at most one in-flight search.
     StartFileSearch(String),
 
+    /// Cancel any in-flight or scheduled file search because the `@` popup
+    /// was closed and its results would be discarded anyway.
+    CancelFileSearch,
+
     /// Result of a completed asynchronous file search. The `query` echoes the
     /// original search term so the UI can decide whether the results are
     /// still relevant.
@@ -309,7 +309,7 @@ impl ChatComposer {
                 if let Some(tok) = Self::current_at_token(&self.textarea) {
                     self.dismissed_file_popup_token = Some(tok.to_string());
                 }
-                self.active_popup = ActivePopup::None;
+                self.close_file_search_popup();
                 (InputResult::None, true)
             }
             KeyEvent {
@@ -324,7 +324,7 @@ impl ChatComposer {
                     let sel_path = sel.to_string();
                     // Drop popup borrow before using self mutably again.
                     self.insert_selected_path(&sel_path);
-                    self.active_popup = ActivePopup::None;
+                    self.close_file_search_popup();
                     return (InputResult::None, true);
                 }
                 (InputResult::None, false)
@@ -574,6 +574,9 @@ impl ChatComposer {
         let query = match Self::current_at_token(&self.textarea) {
             Some(token) => token,
             None => {
+                if matches!(self.active_popup, ActivePopup::File(_)) {
+                    self.app_event_tx.send(AppEvent::CancelFileSearch);
+                }
                 self.active_popup = ActivePopup::None;
                 self.dismissed_file_popup_token = None;
                 return;
@@ -613,6 +616,12 @@ impl ChatComposer {
         self.dismissed_file_popup_token = None;
     }
 
+    /// Hide the file search popup and cancel any search still running for it.
+    fn close_file_search_popup(&mut self) {
+        self.active_popup = ActivePopup::None;
+        self.app_event_tx.send(AppEvent::CancelFileSearch);
+    }
+
     fn set_has_focus(&mut self, has_focus: bool) {
         self.has_focus = has_focus;
     }
@@ -1085,6 +1094,66 @@ mod tests {
         assert_eq!(composer.textarea.text(), "@");
     }
 
+    #[test]
+    fn file_search_result_for_stale_query_is_ignored() {
+        use super::ActivePopup;
+        use codex_file_search::FileMatch;
+        use crossterm::event::KeyCode;
+        use crossterm::event::KeyEvent;
+        use crossterm::event::KeyModifiers;
+
+        let (tx, _rx) = std::sync::mpsc::channel();
+        let sender = AppEventSender::new(tx);
+        let mut composer = ChatComposer::new(true, sender, false);
+
+        for ch in ['@', 'a', 'b'] {
+            let _ = composer.handle_key_event(KeyEvent::new(KeyCode::Char(ch), KeyModifiers::NONE));
+        }
+
+        let file_match = || FileMatch {
+            score: 1,
+            path: "ab.txt".to_string(),
+            indices: None,
+        };
+
+        // Results for the earlier "a" query arrive after the user typed "ab".
+        composer.on_file_search_result("a".to_string(), vec![file_match()]);
+        let ActivePopup::File(popup) = &composer.active_popup else {
+            panic!("expected file search popup");
+        };
+        assert_eq!(popup.selected_match(), None);
+
+        composer.on_file_search_result("ab".to_string(), vec![file_match()]);
+        let ActivePopup::File(popup) = &composer.active_popup else {
+            panic!("expected file search popup");
+        };
+        assert_eq!(popup.selected_match(), Some("ab.txt"));
+    }
+
+    #[test]
+    fn dismissing_file_popup_cancels_file_search() {
+        use crossterm::event::KeyCode;
+        use crossterm::event::KeyEvent;
+        use crossterm::event::KeyModifiers;
+
+        let (tx, rx) = std::sync::mpsc::channel();
+        let sender = AppEventSender::new(tx);
+        let mut composer = ChatComposer::new(true, sender, false);
+
+        for ch in ['@', 'a', 'b'] {
+            let _ = composer.handle_key_event(KeyEvent::new(KeyCode::Char(ch), KeyModifiers::NONE));
+        }
+        while rx.try_recv().is_ok() {}
+
+        let _ = composer.handle_key_event(KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE));
+
+        assert!(
+            rx.try_iter()
+                .any(|event| matches!(event, AppEvent::CancelFileSearch)),
+            "expected a CancelFileSearch event after dismissing the popup"
+        );
+    }
+
     #[test]
     fn test_multiple_pastes_submission() {
         use crossterm::event::KeyCode;
@@ -17,6 +17,7 @@
 //!    recent query.
 //! 4. If there is a in-flight search that is not a prefix of the latest thing
 //!    the user typed, it is cancelled.
+//! 5. If the popup is closed, any in-flight or scheduled search is cancelled.
 
 use codex_file_search as file_search;
 use std::num::NonZeroUsize;
@@ -136,8 +137,12 @@ impl FileSearchManager {
             let query = {
                 #[expect(clippy::unwrap_used)]
                 let mut st = state.lock().unwrap();
-                let query = st.latest_query.clone();
                 st.is_search_scheduled = false;
+                if st.latest_query.is_empty() {
+                    // Cancelled while the debounce timer was pending.
+                    return;
+                }
+                let query = st.latest_query.clone();
                 st.active_search = Some(ActiveSearch {
                     query: query.clone(),
                     cancellation_token: token,
@@ -155,6 +160,21 @@ impl FileSearchManager {
         });
     }
 
+    /// Call when the `@` popup is closed. Cancels the in-flight search, if
+    /// any, and prevents a scheduled search from starting.
+    pub fn cancel(&self) {
+        #[expect(clippy::unwrap_used)]
+        let mut st = self.state.lock().unwrap();
+        if let Some(active_search) = st.active_search.take() {
+            active_search
+                .cancellation_token
+                .store(true, Ordering::Relaxed);
+        }
+        // An empty query is never searched, so a pending debounce timer will
+        // see this and bail out.
+        st.latest_query.clear();
+    }
+
     fn spawn_file_search(
         query: String,
         search_dir: PathBuf,